@@ -1,17 +1,214 @@
 //! M2 model file command implementations
 
 use anyhow::{Context, Result};
-use clap::Subcommand;
-use std::path::PathBuf;
+use clap::{Subcommand, ValueEnum};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
 
 use wow_blp::parser::load_blp;
 use wow_m2::{
-    AnimFile, M2Converter, M2Model, M2Version, Skin,
+    AnimFile, AnimationFileIds, BoneFileIds, ChunkReader, M2Converter, M2Model, M2Version,
+    PhysicsFileId, SkeletonFileId, Skin, SkinFileIds, TextureFileIds,
     skin::{OldSkinHeader, SkinG, SkinHeader, SkinHeaderT},
 };
 
 use crate::utils::{NodeType, TreeNode, TreeOptions, render_tree};
 
+/// Output format for info/validate reports
+#[derive(Copy, Clone, Debug, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text (default)
+    #[default]
+    Text,
+    /// Machine-readable JSON
+    Json,
+    /// Machine-readable YAML
+    Yaml,
+}
+
+impl OutputFormat {
+    /// Serialize `report` and print it in this format, or hand off to `print_text` for `Text`
+    fn emit<T: Serialize>(self, report: &T, print_text: impl FnOnce(&T)) -> Result<()> {
+        match self {
+            OutputFormat::Text => print_text(report),
+            OutputFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(report)?);
+            }
+            OutputFormat::Yaml => {
+                println!("{}", serde_yaml::to_string(report)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Bounding box report shared by the info reports
+#[derive(Serialize)]
+pub struct BoundingBoxReport {
+    pub min: [f32; 3],
+    pub max: [f32; 3],
+}
+
+/// Report emitted by `m2 info`
+#[derive(Serialize)]
+pub struct M2InfoReport {
+    pub file: String,
+    pub version: String,
+    pub name: String,
+    pub flags: u32,
+    pub bounding_box: BoundingBoxReport,
+    pub vertex_count: usize,
+    pub bone_count: usize,
+    pub sequence_count: usize,
+    pub texture_count: usize,
+    pub material_count: usize,
+}
+
+/// Report emitted by `m2 skin-info`
+#[derive(Serialize)]
+pub struct SkinInfoReport {
+    pub file: String,
+    pub indices_count: usize,
+    pub triangle_count: usize,
+    pub submesh_count: usize,
+    pub texture_unit_count: usize,
+}
+
+/// Report emitted by `m2 anim-info`
+#[derive(Serialize)]
+pub struct AnimInfoReport {
+    pub file: String,
+    pub format: String,
+    pub is_legacy: bool,
+    pub section_count: usize,
+    pub total_keyframes: usize,
+    pub approximate_bytes: usize,
+    pub memory_usage: AnimMemoryUsageReport,
+    pub metadata: AnimMetadataReport,
+    pub sections: Vec<AnimSectionReport>,
+}
+
+/// Mirrors `AnimFile::memory_usage()`'s per-kind breakdown
+#[derive(Serialize)]
+pub struct AnimMemoryUsageReport {
+    pub sections: usize,
+    pub bone_animations: usize,
+    pub translation_keyframes: usize,
+    pub rotation_keyframes: usize,
+    pub scaling_keyframes: usize,
+}
+
+/// Format-specific metadata mirroring `AnimFile::metadata`
+#[derive(Serialize)]
+#[serde(tag = "kind")]
+pub enum AnimMetadataReport {
+    Legacy {
+        file_size: u64,
+        animation_count: usize,
+        structure_valid: bool,
+        estimated_blocks: usize,
+        has_timestamps: bool,
+    },
+    Modern {
+        version: u32,
+        id_count: u32,
+        anim_entry_offset: u32,
+        entries: Vec<AnimEntryReport>,
+    },
+}
+
+/// One entry of the `entries` list in the `Modern` variant of [`AnimMetadataReport`]
+#[derive(Serialize)]
+pub struct AnimEntryReport {
+    pub id: u32,
+    pub offset: u32,
+    pub size: u32,
+}
+
+/// One entry of the `sections` breakdown in [`AnimInfoReport`]
+#[derive(Serialize)]
+pub struct AnimSectionReport {
+    pub id: i16,
+    pub start: u32,
+    pub end: u32,
+    pub bone_animation_count: usize,
+}
+
+/// Report emitted by `m2 blp-info`
+#[derive(Serialize)]
+pub struct BlpInfoReport {
+    pub file: String,
+    pub width: u32,
+    pub height: u32,
+    pub pixel_format: String,
+    pub alpha_bits: u8,
+    pub mipmap_count: usize,
+}
+
+/// Report emitted by `m2 validate`
+#[derive(Serialize)]
+pub struct ValidateReport {
+    pub file: String,
+    pub passed: bool,
+    pub error: Option<String>,
+}
+
+/// Per-file result within a [`BatchValidateReport`]
+#[derive(Clone, Serialize)]
+pub struct FileValidationReport {
+    pub path: String,
+    pub file_type: String,
+    pub passed: bool,
+    pub error: Option<String>,
+    /// Normalized bucket for `error`, e.g. "magic", "version", "truncated" (see
+    /// [`classify_validation_error`]); `None` when `passed` is true
+    pub error_category: Option<String>,
+}
+
+/// Report emitted by `m2 batch-validate`
+#[derive(Serialize)]
+pub struct BatchValidateReport {
+    pub root: String,
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub failures_by_file_type: std::collections::BTreeMap<String, usize>,
+    /// Failure counts grouped by normalized error category rather than file extension,
+    /// e.g. to see at a glance whether failures are mostly truncated files vs. bad magic
+    /// bytes regardless of whether they're M2/skin/anim/BLP
+    pub failures_by_category: std::collections::BTreeMap<String, usize>,
+    pub files: Vec<FileValidationReport>,
+}
+
+/// Bucket a validation error message into a coarse, file-type-independent category.
+///
+/// This is a best-effort classification based on substrings already present in the
+/// `anyhow`/format error messages produced by the `validate_*_file` helpers below; it exists
+/// so `batch-validate` can report e.g. "12 magic-byte failures" instead of forcing the
+/// reader to group per-file-type counts by eye.
+fn classify_validation_error(error: &str) -> String {
+    let lower = error.to_lowercase();
+    let category = if lower.contains("magic") || lower.contains("signature") {
+        "magic"
+    } else if lower.contains("version") {
+        "version"
+    } else if lower.contains("offset") || lower.contains("out of bounds") || lower.contains("out-of-bounds") {
+        "offset"
+    } else if lower.contains("truncated") || lower.contains("unexpected end") || lower.contains("eof") {
+        "truncated"
+    } else if lower.contains("utf-8") || lower.contains("utf8") {
+        "encoding"
+    } else if lower.contains("permission denied") {
+        "permission"
+    } else if lower.contains("no such file") || lower.contains("not found") {
+        "missing"
+    } else {
+        "other"
+    };
+    category.to_string()
+}
+
 #[derive(Subcommand)]
 pub enum M2Commands {
     /// Display information about an M2 model file
@@ -22,6 +219,10 @@ pub enum M2Commands {
         /// Show detailed information
         #[arg(short, long)]
         detailed: bool,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
     },
 
     /// Validate an M2 model file
@@ -32,6 +233,10 @@ pub enum M2Commands {
         /// Show all warnings (not just errors)
         #[arg(short, long)]
         warnings: bool,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
     },
 
     /// Convert an M2 model to a different version
@@ -77,6 +282,10 @@ pub enum M2Commands {
         /// Parse old format
         #[arg(short, long)]
         old_format: bool,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
     },
 
     /// Convert a Skin file to a different version
@@ -100,6 +309,10 @@ pub enum M2Commands {
         /// Show detailed information
         #[arg(short, long)]
         detailed: bool,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
     },
 
     /// Convert an ANIM file to a different version
@@ -123,18 +336,84 @@ pub enum M2Commands {
         /// Show detailed information
         #[arg(short, long)]
         detailed: bool,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+
+    /// Export an M2 model (geometry, skin and animations) to glTF 2.0
+    ExportGltf {
+        /// Input M2 file
+        input: PathBuf,
+
+        /// Output .gltf or .glb file
+        output: PathBuf,
+
+        /// Skin file to use for submesh/triangle indices (defaults to `<input stem>00.skin`)
+        #[arg(long)]
+        skin: Option<PathBuf>,
+
+        /// Decode referenced BLP textures and embed them as PNG images
+        #[arg(long)]
+        embed_textures: bool,
+    },
+
+    /// Enumerate and locate an M2's companion files (.skin/.anim/.bone/.phys/.skel)
+    Deps {
+        /// Path to the M2 file
+        file: PathBuf,
+
+        /// Directory holding files named by FileDataID (for Legion+ models)
+        #[arg(long)]
+        resolve_dir: Option<PathBuf>,
+    },
+
+    /// Recursively validate every .m2/.skin/.anim/.blp file under a directory
+    BatchValidate {
+        /// Root directory to scan
+        root: PathBuf,
+
+        /// Show all files in the detail section, not just failures
+        #[arg(short, long)]
+        warnings: bool,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+
+    /// Decode a BLP texture (and its mipmap chain) to a standard image format
+    BlpConvert {
+        /// Input BLP file
+        input: PathBuf,
+
+        /// Output image file (format inferred from extension, e.g. .png, .tga)
+        output: PathBuf,
+
+        /// Mip level to decode (defaults to the base/full-resolution level)
+        #[arg(long)]
+        mip: Option<usize>,
     },
 }
 
 pub fn execute(cmd: M2Commands) -> Result<()> {
     match cmd {
-        M2Commands::Info { file, detailed } => handle_info(file, detailed),
+        M2Commands::Info {
+            file,
+            detailed,
+            format,
+        } => handle_info(file, detailed, format),
         M2Commands::Convert {
             input,
             output,
             version,
         } => handle_convert(input, output, version),
-        M2Commands::Validate { file, warnings } => handle_validate(file, warnings),
+        M2Commands::Validate {
+            file,
+            warnings,
+            format,
+        } => handle_validate(file, warnings, format),
         M2Commands::Tree {
             file,
             depth,
@@ -145,11 +424,12 @@ pub fn execute(cmd: M2Commands) -> Result<()> {
             file,
             detailed,
             old_format,
+            format,
         } => {
             if old_format {
-                handle_skin_info::<OldSkinHeader>(file, detailed)
+                handle_skin_info::<OldSkinHeader>(file, detailed, format)
             } else {
-                handle_skin_info::<SkinHeader>(file, detailed)
+                handle_skin_info::<SkinHeader>(file, detailed, format)
             }
         }
         M2Commands::SkinConvert {
@@ -157,35 +437,88 @@ pub fn execute(cmd: M2Commands) -> Result<()> {
             output,
             version,
         } => handle_skin_convert(input, output, version),
-        M2Commands::AnimInfo { file, detailed } => handle_anim_info(file, detailed),
+        M2Commands::AnimInfo {
+            file,
+            detailed,
+            format,
+        } => handle_anim_info(file, detailed, format),
         M2Commands::AnimConvert {
             input,
             output,
             version,
         } => handle_anim_convert(input, output, version),
-        M2Commands::BlpInfo { file, detailed } => handle_blp_info(file, detailed),
+        M2Commands::BlpInfo {
+            file,
+            detailed,
+            format,
+        } => handle_blp_info(file, detailed, format),
+        M2Commands::ExportGltf {
+            input,
+            output,
+            skin,
+            embed_textures,
+        } => handle_export_gltf(input, output, skin, embed_textures),
+        M2Commands::Deps { file, resolve_dir } => handle_deps(file, resolve_dir),
+        M2Commands::BatchValidate {
+            root,
+            warnings,
+            format,
+        } => handle_batch_validate(root, warnings, format),
+        M2Commands::BlpConvert { input, output, mip } => handle_blp_convert(input, output, mip),
     }
 }
 
-fn handle_info(path: PathBuf, detailed: bool) -> Result<()> {
-    println!("Loading M2 model: {}", path.display());
+fn handle_info(path: PathBuf, detailed: bool, format: OutputFormat) -> Result<()> {
+    if matches!(format, OutputFormat::Text) {
+        println!("Loading M2 model: {}", path.display());
+    }
 
-    let _model = M2Model::load(&path)
+    let m2_format = M2Model::load(&path)
         .with_context(|| format!("Failed to load M2 model from {}", path.display()))?;
+    let model = m2_format.model();
+    let bounds = model.bounding_box();
+
+    let report = M2InfoReport {
+        file: path.display().to_string(),
+        version: format!("{:?}", model.version()),
+        name: model.name().to_string(),
+        flags: model.flags(),
+        bounding_box: BoundingBoxReport {
+            min: bounds.min,
+            max: bounds.max,
+        },
+        vertex_count: model.vertices().len(),
+        bone_count: model.bones().len(),
+        sequence_count: model.sequences().len(),
+        texture_count: model.textures().len(),
+        material_count: model.materials().len(),
+    };
 
-    println!("\n=== M2 Model Information ===");
-
-    // Note: Many fields are private in the M2Model struct, so we can only show basic info
-    // The actual model implementation would need to expose more public methods/fields
-
-    println!("File loaded successfully!");
-
-    if detailed {
-        println!("\n=== Detailed Information ===");
-        println!("(Detailed information requires additional public API methods)");
-    }
-
-    Ok(())
+    format.emit(&report, |report| {
+        println!("\n=== M2 Model Information ===");
+        println!("Name: {}", report.name);
+        println!("Version: {}", report.version);
+        println!("Flags: {:#x}", report.flags);
+        println!(
+            "Bounding box: min={:?} max={:?}",
+            report.bounding_box.min, report.bounding_box.max
+        );
+        println!("Vertices: {}", report.vertex_count);
+        println!("Bones: {}", report.bone_count);
+        println!("Sequences: {}", report.sequence_count);
+        println!("Textures: {}", report.texture_count);
+        println!("Materials: {}", report.material_count);
+
+        if detailed {
+            println!("\n=== Detailed Information ===");
+            for (i, texture) in model.textures().iter().enumerate() {
+                println!("Texture {i}: {texture:?}");
+            }
+            for (i, sequence) in model.sequences().iter().enumerate() {
+                println!("Sequence {i}: {sequence:?}");
+            }
+        }
+    })
 }
 
 fn handle_convert(input: PathBuf, output: PathBuf, version_str: String) -> Result<()> {
@@ -214,42 +547,64 @@ fn handle_convert(input: PathBuf, output: PathBuf, version_str: String) -> Resul
     Ok(())
 }
 
-fn handle_validate(path: PathBuf, show_warnings: bool) -> Result<()> {
-    println!("Validating M2 model: {}", path.display());
+fn handle_validate(path: PathBuf, show_warnings: bool, format: OutputFormat) -> Result<()> {
+    if matches!(format, OutputFormat::Text) {
+        println!("Validating M2 model: {}", path.display());
+    }
 
     let m2_format = M2Model::load(&path)
         .with_context(|| format!("Failed to load M2 model from {}", path.display()))?;
     let model = m2_format.model();
 
-    // Validate the model
-    match model.validate() {
-        Ok(_) => {
+    let validation = model.validate();
+    let report = ValidateReport {
+        file: path.display().to_string(),
+        passed: validation.is_ok(),
+        error: validation.as_ref().err().map(|e| e.to_string()),
+    };
+
+    format.emit(&report, |report| {
+        if report.passed {
             println!("✓ Model validation passed!");
-        }
-        Err(e) => {
-            println!("❌ Model validation failed: {e}");
+        } else {
+            println!("❌ Model validation failed: {}", report.error.as_deref().unwrap_or(""));
             if !show_warnings {
                 println!("Use --warnings to show additional details");
             }
-            std::process::exit(1);
         }
+    })?;
+
+    if validation.is_err() {
+        std::process::exit(1);
     }
 
     Ok(())
 }
 
-fn handle_tree(path: PathBuf, max_depth: usize, _show_size: bool, _show_refs: bool) -> Result<()> {
-    let _model = M2Model::load(&path)
-        .with_context(|| format!("Failed to load M2 model from {}", path.display()))?;
+/// FileDataID chunks that follow the `MD21` chunk in a Legion+ model
+const FILE_ID_CHUNKS: &[&str] = &["SFID", "AFID", "BFID", "TXID", "PFID", "SKID"];
 
-    let root = TreeNode::new("M2 Model".to_string(), NodeType::Root);
+fn handle_tree(path: PathBuf, max_depth: usize, show_size: bool, show_refs: bool) -> Result<()> {
+    let data =
+        std::fs::read(&path).with_context(|| format!("Failed to read {}", path.display()))?;
 
-    // Since most model fields are private, we can only show a basic structure
-    // A real implementation would need the M2Model to expose more information
+    let label = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "M2 Model".to_string());
+    let mut root = TreeNode::new(label, NodeType::Root);
+
+    if data.get(0..4) == Some(b"MD20") {
+        let m2_format = M2Model::load(&path)
+            .with_context(|| format!("Failed to load M2 model from {}", path.display()))?;
+        add_model_array_nodes(&mut root, m2_format.model(), show_size, show_refs);
+    } else {
+        add_chunked_nodes(&mut root, &data, show_size, show_refs)?;
+    }
 
     let options = TreeOptions {
         max_depth: Some(max_depth),
-        show_external_refs: _show_refs,
+        show_external_refs: show_refs,
         no_color: false,
         show_metadata: true,
         compact: false,
@@ -258,27 +613,143 @@ fn handle_tree(path: PathBuf, max_depth: usize, _show_size: bool, _show_refs: bo
     let tree_output = render_tree(&root, &options);
     print!("{tree_output}");
 
-    println!("\n(Note: Full tree visualization requires additional public API methods)");
     Ok(())
 }
 
-fn handle_skin_info<H: SkinHeaderT + Clone>(path: PathBuf, detailed: bool) -> Result<()> {
-    println!("Loading Skin file: {}", path.display());
+/// Render the classic-header M2Array sub-tables (vertices, bones, sequences, ...) as children.
+///
+/// Byte offset/size come from `M2ModelData::array_info`, which reads the on-disk `M2Array`
+/// (count, offset) pair directly — not from `size_of` of the parsed Rust type, which need not
+/// match the on-disk element layout.
+fn add_model_array_nodes(
+    parent: &mut TreeNode,
+    model: &wow_m2::M2ModelData,
+    show_size: bool,
+    show_refs: bool,
+) {
+    use wow_m2::M2ArrayField;
+
+    let arrays: &[(&str, usize, M2ArrayField)] = &[
+        ("vertices", model.vertices().len(), M2ArrayField::Vertices),
+        ("bones", model.bones().len(), M2ArrayField::Bones),
+        ("sequences", model.sequences().len(), M2ArrayField::Sequences),
+        ("textures", model.textures().len(), M2ArrayField::Textures),
+        ("materials", model.materials().len(), M2ArrayField::Materials),
+        (
+            "attachments",
+            model.attachments().len(),
+            M2ArrayField::Attachments,
+        ),
+        ("cameras", model.cameras().len(), M2ArrayField::Cameras),
+        ("lights", model.lights().len(), M2ArrayField::Lights),
+        (
+            "particle_emitters",
+            model.particle_emitters().len(),
+            M2ArrayField::ParticleEmitters,
+        ),
+        (
+            "ribbon_emitters",
+            model.ribbon_emitters().len(),
+            M2ArrayField::RibbonEmitters,
+        ),
+    ];
+
+    for (name, count, field) in arrays {
+        let mut label = format!("{name}: {count}");
+
+        if show_size || show_refs {
+            let info = model.array_info(*field);
+            if show_size {
+                label = format!("{label} ({} bytes)", info.byte_size);
+            }
+            if show_refs {
+                label = format!(
+                    "{label} [header=0x{:x}, data=0x{:x}]",
+                    info.header_offset, info.data_offset
+                );
+            }
+        }
 
-    let _skin = SkinG::<H>::load(&path)
-        .with_context(|| format!("Failed to load Skin file from {}", path.display()))?;
+        parent.add_child(TreeNode::new(label, NodeType::Array));
+    }
+}
 
-    println!("\n=== Skin Information ===");
-    println!("File loaded successfully!");
+/// Render the Legion+ chunked container: the `MD21` chunk followed by `*FID` sibling chunks
+fn add_chunked_nodes(
+    root: &mut TreeNode,
+    data: &[u8],
+    show_size: bool,
+    show_refs: bool,
+) -> Result<()> {
+    let mut reader = ChunkReader::new(data);
+
+    while let Some(header) = reader.read_header()? {
+        let magic = header.magic_str();
+        let chunk_data = reader.read_chunk_data(&header)?;
+
+        let mut label = format!("{magic} chunk");
+        if show_size {
+            label = format!("{label} ({} bytes)", header.size);
+        }
+        let mut node = TreeNode::new(label, NodeType::Chunk);
+
+        if magic == "MD21" {
+            let m2_format = wow_m2::M2Model::load_from_md21(chunk_data).with_context(|| {
+                "Failed to parse MD21 chunk (all offsets are relative to the chunk start)"
+            })?;
+            add_model_array_nodes(&mut node, m2_format.model(), show_size, show_refs);
+        } else if show_refs && FILE_ID_CHUNKS.contains(&magic.as_str()) {
+            for (i, chunk) in chunk_data.chunks_exact(4).enumerate() {
+                let file_data_id = u32::from_le_bytes(chunk.try_into().unwrap());
+                node.add_child(TreeNode::new(
+                    format!("[{i}] FileDataID {file_data_id}"),
+                    NodeType::Reference,
+                ));
+            }
+        }
 
-    if detailed {
-        println!("\n=== Detailed Information ===");
-        println!("(Detailed information requires additional public API methods)");
+        root.add_child(node);
     }
 
     Ok(())
 }
 
+fn handle_skin_info<H: SkinHeaderT + Clone>(
+    path: PathBuf,
+    detailed: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    if matches!(format, OutputFormat::Text) {
+        println!("Loading Skin file: {}", path.display());
+    }
+
+    let skin = SkinG::<H>::load(&path)
+        .with_context(|| format!("Failed to load Skin file from {}", path.display()))?;
+
+    let report = SkinInfoReport {
+        file: path.display().to_string(),
+        indices_count: skin.indices().len(),
+        triangle_count: skin.triangles().len(),
+        submesh_count: skin.submeshes().len(),
+        texture_unit_count: skin.texture_units().len(),
+    };
+
+    format.emit(&report, |report| {
+        println!("\n=== Skin Information ===");
+        println!("Indices: {}", report.indices_count);
+        println!("Triangles: {}", report.triangle_count);
+        println!("Submeshes: {}", report.submesh_count);
+        println!("Texture units: {}", report.texture_unit_count);
+
+        if detailed {
+            println!("\n=== Detailed Information ===");
+            for (i, submesh) in skin.submeshes().iter().enumerate() {
+                println!("Submesh {i}: {submesh:?}");
+            }
+        }
+    })
+}
+
 fn handle_skin_convert(input: PathBuf, output: PathBuf, version_str: String) -> Result<()> {
     println!("Loading Skin file: {}", input.display());
 
@@ -298,86 +769,150 @@ fn handle_skin_convert(input: PathBuf, output: PathBuf, version_str: String) ->
     Ok(())
 }
 
-fn handle_anim_info(path: PathBuf, detailed: bool) -> Result<()> {
-    println!("Loading ANIM file: {}", path.display());
+fn handle_anim_info(path: PathBuf, detailed: bool, format: OutputFormat) -> Result<()> {
+    if matches!(format, OutputFormat::Text) {
+        println!("Loading ANIM file: {}", path.display());
+    }
 
     let anim = AnimFile::load(&path)
         .with_context(|| format!("Failed to load ANIM file from {}", path.display()))?;
 
-    println!("\n=== ANIM Information ===");
-    println!("Format: {:?}", anim.format);
-    println!("Animation Sections: {}", anim.animation_count());
+    let usage = anim.memory_usage();
+    let metadata = match &anim.metadata {
+        wow_m2::AnimMetadata::Legacy {
+            file_size,
+            animation_count,
+            structure_hints,
+        } => AnimMetadataReport::Legacy {
+            file_size: *file_size,
+            animation_count: *animation_count,
+            structure_valid: structure_hints.appears_valid,
+            estimated_blocks: structure_hints.estimated_blocks,
+            has_timestamps: structure_hints.has_timestamps,
+        },
+        wow_m2::AnimMetadata::Modern { header, entries } => AnimMetadataReport::Modern {
+            version: header.version,
+            id_count: header.id_count,
+            anim_entry_offset: header.anim_entry_offset,
+            entries: entries
+                .iter()
+                .map(|entry| AnimEntryReport {
+                    id: entry.id,
+                    offset: entry.offset,
+                    size: entry.size,
+                })
+                .collect(),
+        },
+    };
 
-    if anim.is_legacy_format() {
-        println!("Legacy Format: True");
-    } else {
-        println!("Modern Format: True");
-    }
+    let report = AnimInfoReport {
+        file: path.display().to_string(),
+        format: format!("{:?}", anim.format),
+        is_legacy: anim.is_legacy_format(),
+        section_count: anim.animation_count(),
+        total_keyframes: usage.total_keyframes(),
+        approximate_bytes: usage.approximate_bytes,
+        memory_usage: AnimMemoryUsageReport {
+            sections: usage.sections,
+            bone_animations: usage.bone_animations,
+            translation_keyframes: usage.translation_keyframes,
+            rotation_keyframes: usage.rotation_keyframes,
+            scaling_keyframes: usage.scaling_keyframes,
+        },
+        metadata,
+        sections: anim
+            .sections
+            .iter()
+            .map(|section| AnimSectionReport {
+                id: section.header.id,
+                start: section.header.start,
+                end: section.header.end,
+                bone_animation_count: section.bone_animations.len(),
+            })
+            .collect(),
+    };
 
-    // Show memory usage stats
-    let usage = anim.memory_usage();
-    println!("Total Keyframes: {}", usage.total_keyframes());
-    println!("Memory Usage: ~{} bytes", usage.approximate_bytes);
-
-    if detailed {
-        println!("\n=== Detailed Information ===");
-
-        // Show format-specific metadata
-        match &anim.metadata {
-            wow_m2::AnimMetadata::Legacy {
-                file_size,
-                animation_count,
-                structure_hints,
-            } => {
-                println!("File Size: {} bytes", file_size);
-                println!("Animation Count (metadata): {}", animation_count);
-                println!("Structure Valid: {}", structure_hints.appears_valid);
-                println!("Estimated Blocks: {}", structure_hints.estimated_blocks);
-                println!("Has Timestamps: {}", structure_hints.has_timestamps);
-            }
-            wow_m2::AnimMetadata::Modern { header, entries } => {
-                println!("ANIM Version: {}", header.version);
-                println!("ID Count: {}", header.id_count);
-                println!("Entry Offset: {}", header.anim_entry_offset);
-                println!("Entry Count: {}", entries.len());
-
-                if !entries.is_empty() {
-                    println!("\n=== Animation Entries ===");
-                    for (i, entry) in entries.iter().enumerate() {
-                        println!(
-                            "Entry {}: ID={}, Offset={}, Size={}",
-                            i, entry.id, entry.offset, entry.size
-                        );
+    format.emit(&report, |report| {
+        println!("\n=== ANIM Information ===");
+        println!("Format: {}", report.format);
+        println!("Animation Sections: {}", report.section_count);
+        println!(
+            "{} Format: True",
+            if report.is_legacy { "Legacy" } else { "Modern" }
+        );
+        println!("Total Keyframes: {}", report.total_keyframes);
+        println!("Memory Usage: ~{} bytes", report.approximate_bytes);
+
+        if detailed {
+            println!("\n=== Detailed Information ===");
+
+            // Show format-specific metadata
+            match &report.metadata {
+                AnimMetadataReport::Legacy {
+                    file_size,
+                    animation_count,
+                    structure_valid,
+                    estimated_blocks,
+                    has_timestamps,
+                } => {
+                    println!("File Size: {} bytes", file_size);
+                    println!("Animation Count (metadata): {}", animation_count);
+                    println!("Structure Valid: {}", structure_valid);
+                    println!("Estimated Blocks: {}", estimated_blocks);
+                    println!("Has Timestamps: {}", has_timestamps);
+                }
+                AnimMetadataReport::Modern {
+                    version,
+                    id_count,
+                    anim_entry_offset,
+                    entries,
+                } => {
+                    println!("ANIM Version: {}", version);
+                    println!("ID Count: {}", id_count);
+                    println!("Entry Offset: {}", anim_entry_offset);
+                    println!("Entry Count: {}", entries.len());
+
+                    if !entries.is_empty() {
+                        println!("\n=== Animation Entries ===");
+                        for (i, entry) in entries.iter().enumerate() {
+                            println!(
+                                "Entry {}: ID={}, Offset={}, Size={}",
+                                i, entry.id, entry.offset, entry.size
+                            );
+                        }
                     }
                 }
             }
-        }
 
-        // Show memory breakdown
-        println!("\n=== Memory Usage Breakdown ===");
-        println!("Sections: {}", usage.sections);
-        println!("Bone Animations: {}", usage.bone_animations);
-        println!("Translation Keyframes: {}", usage.translation_keyframes);
-        println!("Rotation Keyframes: {}", usage.rotation_keyframes);
-        println!("Scaling Keyframes: {}", usage.scaling_keyframes);
-
-        // Show sections summary
-        if !anim.sections.is_empty() {
-            println!("\n=== Animation Sections ===");
-            for (i, section) in anim.sections.iter().enumerate() {
-                println!(
-                    "Section {}: ID={}, Start={}, End={}, Bones={}",
-                    i,
-                    section.header.id,
-                    section.header.start,
-                    section.header.end,
-                    section.bone_animations.len()
-                );
+            // Show memory breakdown
+            println!("\n=== Memory Usage Breakdown ===");
+            println!("Sections: {}", report.memory_usage.sections);
+            println!("Bone Animations: {}", report.memory_usage.bone_animations);
+            println!(
+                "Translation Keyframes: {}",
+                report.memory_usage.translation_keyframes
+            );
+            println!(
+                "Rotation Keyframes: {}",
+                report.memory_usage.rotation_keyframes
+            );
+            println!(
+                "Scaling Keyframes: {}",
+                report.memory_usage.scaling_keyframes
+            );
+
+            // Show sections summary
+            if !report.sections.is_empty() {
+                println!("\n=== Animation Sections ===");
+                for (i, section) in report.sections.iter().enumerate() {
+                    println!(
+                        "Section {}: ID={}, Start={}, End={}, Bones={}",
+                        i, section.id, section.start, section.end, section.bone_animation_count
+                    );
+                }
             }
         }
-    }
-
-    Ok(())
+    })
 }
 
 fn handle_anim_convert(input: PathBuf, output: PathBuf, version_str: String) -> Result<()> {
@@ -408,19 +943,972 @@ fn handle_anim_convert(input: PathBuf, output: PathBuf, version_str: String) ->
     Ok(())
 }
 
-fn handle_blp_info(path: PathBuf, detailed: bool) -> Result<()> {
-    println!("Loading BLP texture: {}", path.display());
+fn handle_blp_info(path: PathBuf, detailed: bool, format: OutputFormat) -> Result<()> {
+    if matches!(format, OutputFormat::Text) {
+        println!("Loading BLP texture: {}", path.display());
+    }
 
-    let _blp = load_blp(&path)
+    let blp = load_blp(&path)
         .with_context(|| format!("Failed to load BLP texture from {}", path.display()))?;
 
-    println!("\n=== BLP Texture Information ===");
-    println!("File loaded successfully!");
+    let report = BlpInfoReport {
+        file: path.display().to_string(),
+        width: blp.header.width,
+        height: blp.header.height,
+        pixel_format: format!("{:?}", blp.header.content),
+        alpha_bits: blp.header.flags.alpha_bits,
+        mipmap_count: blp.mipmaps_count(),
+    };
+
+    format.emit(&report, |report| {
+        println!("\n=== BLP Texture Information ===");
+        println!("Dimensions: {}x{}", report.width, report.height);
+        println!("Pixel format: {}", report.pixel_format);
+        println!("Alpha depth: {} bits", report.alpha_bits);
+        println!("Mip levels: {}", report.mipmap_count);
+
+        if detailed {
+            println!("\n=== Detailed Information ===");
+            println!("Has alpha: {}", report.alpha_bits > 0);
+        }
+    })
+}
 
-    if detailed {
-        println!("\n=== Detailed Information ===");
-        println!("(Detailed information requires additional public API methods)");
+fn handle_export_gltf(
+    input: PathBuf,
+    output: PathBuf,
+    skin_path: Option<PathBuf>,
+    embed_textures: bool,
+) -> Result<()> {
+    println!("Loading M2 model: {}", input.display());
+
+    let m2_format = M2Model::load(&input)
+        .with_context(|| format!("Failed to load M2 model from {}", input.display()))?;
+    let model = m2_format.model();
+
+    let skin_path = skin_path.unwrap_or_else(|| default_skin_path(&input));
+    println!("Loading skin: {}", skin_path.display());
+    let skin = Skin::load(&skin_path)
+        .with_context(|| format!("Failed to load Skin file from {}", skin_path.display()))?;
+
+    let mut builder = gltf_export::GltfBuilder::new();
+    builder.add_skeleton(model.bones());
+    builder.add_materials(model.textures());
+    builder.add_mesh(model.vertices(), &skin);
+
+    for sequence in model.sequences() {
+        builder.add_animation(sequence, model.bones());
+    }
+
+    if embed_textures {
+        println!("Decoding and embedding referenced textures...");
+        for (texture_index, texture) in model.textures().iter().enumerate() {
+            let texture_path = input.with_file_name(texture.filename());
+            match load_blp(&texture_path) {
+                Ok(blp) => builder.add_texture(texture_index, texture.filename(), &blp),
+                Err(e) => {
+                    println!("Warning: could not load texture {texture_path:?}: {e}");
+                }
+            }
+        }
     }
 
+    println!("Writing glTF asset to: {}", output.display());
+    builder
+        .write(&output)
+        .with_context(|| format!("Failed to write glTF asset to {}", output.display()))?;
+
+    println!("Export complete!");
+    Ok(())
+}
+
+/// Default companion-skin path for a model, e.g. `Model.m2` -> `Model00.skin`
+fn default_skin_path(m2_path: &std::path::Path) -> PathBuf {
+    let stem = m2_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    m2_path.with_file_name(format!("{stem}00.skin"))
+}
+
+/// glTF 2.0 asset builder used by [`handle_export_gltf`].
+///
+/// Geometry, the skeleton and animations all land in one little-endian binary blob
+/// (`GltfBuilder::binary`); each `push_*` helper appends to it and records the matching
+/// `bufferView` (plus an `accessor`, for the accessor helpers) in `root`, which is what
+/// `write` finally serializes.
+mod gltf_export {
+    use anyhow::Result;
+    use std::path::Path;
+
+    use wow_m2::{BoneNode, M2Sequence, M2Vertex, Skin};
+
+    /// Accumulates M2 geometry/skeleton/animation data and writes it as a glTF/GLB asset
+    #[derive(Default)]
+    pub struct GltfBuilder {
+        root: gltf_json::Root,
+        binary: Vec<u8>,
+        mesh_node: Option<gltf_json::Index<gltf_json::Node>>,
+        joint_nodes: Vec<gltf_json::Index<gltf_json::Node>>,
+        root_joints: Vec<gltf_json::Index<gltf_json::Node>>,
+        texture_materials: Vec<gltf_json::Index<gltf_json::Material>>,
+        skin: Option<gltf_json::Index<gltf_json::Skin>>,
+    }
+
+    impl GltfBuilder {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Map the M2 bone hierarchy to glTF nodes (parented per `BoneNode::parent_bone`) plus
+        /// a `Skin` whose inverse-bind matrices are each bone's inverted rest-pose translation
+        pub fn add_skeleton(&mut self, bones: &[BoneNode]) {
+            if bones.is_empty() {
+                return;
+            }
+
+            let base = self.root.nodes.len() as u32;
+            for bone in bones {
+                // glTF node transforms are relative to their parent, but `pivot` is a
+                // model-space (absolute) position, so subtract the parent's pivot to get
+                // the local translation. Root bones keep their pivot as-is.
+                let parent_pivot = bones
+                    .get(usize::try_from(bone.parent_bone).unwrap_or(usize::MAX))
+                    .map_or([0.0, 0.0, 0.0], |parent| parent.pivot);
+                let local_translation = [
+                    bone.pivot[0] - parent_pivot[0],
+                    bone.pivot[1] - parent_pivot[1],
+                    bone.pivot[2] - parent_pivot[2],
+                ];
+                self.root.nodes.push(gltf_json::Node {
+                    translation: Some(local_translation),
+                    ..Default::default()
+                });
+            }
+            for (i, bone) in bones.iter().enumerate() {
+                if bone.parent_bone >= 0 {
+                    let parent = base as usize + bone.parent_bone as usize;
+                    let child = gltf_json::Index::new(base + i as u32);
+                    self.root.nodes[parent]
+                        .children
+                        .get_or_insert_with(Vec::new)
+                        .push(child);
+                }
+            }
+            self.joint_nodes = (0..bones.len())
+                .map(|i| gltf_json::Index::new(base + i as u32))
+                .collect();
+            self.root_joints = bones
+                .iter()
+                .zip(&self.joint_nodes)
+                .filter(|(bone, _)| bone.parent_bone < 0)
+                .map(|(_, &joint)| joint)
+                .collect();
+
+            let inverse_bind_matrices: Vec<[f32; 16]> = bones
+                .iter()
+                .map(|bone| inverse_translation_matrix(bone.pivot))
+                .collect();
+            let ibm_accessor = self.push_accessor(
+                &inverse_bind_matrices,
+                gltf_json::accessor::Type::Mat4,
+                None,
+            );
+
+            let skin_index = gltf_json::Index::new(self.root.skins.len() as u32);
+            self.root.skins.push(gltf_json::Skin {
+                inverse_bind_matrices: Some(ibm_accessor),
+                joints: self.joint_nodes.clone(),
+                skeleton: self.joint_nodes.first().copied(),
+                ..Default::default()
+            });
+            self.skin = Some(skin_index);
+        }
+
+        /// Map M2 vertices (position, normal, two UV sets, bone weights/indices) and the
+        /// skin's submesh/triangle indices to a single glTF mesh primitive per submesh
+        pub fn add_mesh(&mut self, vertices: &[M2Vertex], skin: &Skin) {
+            if vertices.is_empty() {
+                return;
+            }
+
+            let positions: Vec<[f32; 3]> = vertices.iter().map(|v| v.position).collect();
+            let normals: Vec<[f32; 3]> = vertices.iter().map(|v| v.normal).collect();
+            let uv0: Vec<[f32; 2]> = vertices.iter().map(|v| v.tex_coords).collect();
+            let uv1: Vec<[f32; 2]> = vertices.iter().map(|v| v.tex_coords2).collect();
+            let joints: Vec<[u16; 4]> = vertices
+                .iter()
+                .map(|v| v.bone_indices.map(u16::from))
+                .collect();
+            let weights: Vec<[f32; 4]> = vertices
+                .iter()
+                .map(|v| v.bone_weights.map(|w| f32::from(w) / 255.0))
+                .collect();
+
+            let mut attributes = std::collections::BTreeMap::new();
+            attributes.insert(
+                gltf_json::validation::Checked::Valid(gltf_json::mesh::Semantic::Positions),
+                self.push_accessor(&positions, gltf_json::accessor::Type::Vec3, Some(true)),
+            );
+            attributes.insert(
+                gltf_json::validation::Checked::Valid(gltf_json::mesh::Semantic::Normals),
+                self.push_accessor(&normals, gltf_json::accessor::Type::Vec3, None),
+            );
+            attributes.insert(
+                gltf_json::validation::Checked::Valid(gltf_json::mesh::Semantic::TexCoords(0)),
+                self.push_accessor(&uv0, gltf_json::accessor::Type::Vec2, None),
+            );
+            attributes.insert(
+                gltf_json::validation::Checked::Valid(gltf_json::mesh::Semantic::TexCoords(1)),
+                self.push_accessor(&uv1, gltf_json::accessor::Type::Vec2, None),
+            );
+            attributes.insert(
+                gltf_json::validation::Checked::Valid(gltf_json::mesh::Semantic::Joints(0)),
+                self.push_accessor(&joints, gltf_json::accessor::Type::Vec4, None),
+            );
+            attributes.insert(
+                gltf_json::validation::Checked::Valid(gltf_json::mesh::Semantic::Weights(0)),
+                self.push_accessor(&weights, gltf_json::accessor::Type::Vec4, None),
+            );
+
+            // Every submesh becomes its own primitive, sharing the vertex buffer above.
+            // `skin.triangles()` is the winding-ordered triangle list; its values are local
+            // indices into `skin.indices()`, the vertex-remap table that resolves to global
+            // vertex ids. `index_start`/`index_count` are offsets into the triangle list, not
+            // the remap table, so both must be applied in that order.
+            let vertex_remap = skin.indices();
+            let triangles = skin.triangles();
+            let mut primitives = Vec::new();
+            for (submesh_index, submesh) in skin.submeshes().iter().enumerate() {
+                let submesh_indices: Vec<u16> = triangles
+                    [submesh.index_start()..submesh.index_start() + submesh.index_count()]
+                    .iter()
+                    .map(|&local_index| vertex_remap[local_index as usize])
+                    .collect();
+                let indices_accessor =
+                    self.push_scalar_accessor(&submesh_indices, gltf_json::accessor::ComponentType::U16);
+
+                let material = skin
+                    .texture_units()
+                    .iter()
+                    .find(|unit| unit.submesh_index() as usize == submesh_index)
+                    .and_then(|unit| self.texture_materials.get(unit.texture_index() as usize))
+                    .copied();
+
+                primitives.push(gltf_json::mesh::Primitive {
+                    attributes: attributes.clone(),
+                    indices: Some(indices_accessor),
+                    material,
+                    mode: gltf_json::validation::Checked::Valid(gltf_json::mesh::Mode::Triangles),
+                    targets: None,
+                    extensions: Default::default(),
+                    extras: Default::default(),
+                });
+            }
+
+            let mesh_index = gltf_json::Index::new(self.root.meshes.len() as u32);
+            self.root.meshes.push(gltf_json::Mesh {
+                primitives,
+                ..Default::default()
+            });
+
+            let node_index = gltf_json::Index::new(self.root.nodes.len() as u32);
+            self.root.nodes.push(gltf_json::Node {
+                mesh: Some(mesh_index),
+                skin: self.skin,
+                ..Default::default()
+            });
+            self.mesh_node = Some(node_index);
+        }
+
+        /// Sample a sequence's per-bone translation/rotation/scale tracks into glTF animation
+        /// channels, one sampler per (bone, property) pair that has keyframes for this sequence
+        pub fn add_animation(&mut self, sequence: &M2Sequence, bones: &[BoneNode]) {
+            if self.joint_nodes.len() != bones.len() {
+                return;
+            }
+
+            let mut channels = Vec::new();
+            let mut samplers = Vec::new();
+
+            for (bone, &joint) in bones.iter().zip(&self.joint_nodes) {
+                self.add_track_channels(
+                    bone.translation_track(sequence.id),
+                    joint,
+                    gltf_json::animation::Property::Translation,
+                    &mut channels,
+                    &mut samplers,
+                );
+                self.add_track_channels(
+                    bone.rotation_track(sequence.id),
+                    joint,
+                    gltf_json::animation::Property::Rotation,
+                    &mut channels,
+                    &mut samplers,
+                );
+                self.add_track_channels(
+                    bone.scale_track(sequence.id),
+                    joint,
+                    gltf_json::animation::Property::Scale,
+                    &mut channels,
+                    &mut samplers,
+                );
+            }
+
+            if channels.is_empty() {
+                return;
+            }
+
+            self.root.animations.push(gltf_json::Animation {
+                name: Some(format!("sequence_{}_{}", sequence.id, sequence.variation_index)),
+                channels,
+                samplers,
+                extensions: Default::default(),
+                extras: Default::default(),
+            });
+        }
+
+        fn add_track_channels<const N: usize>(
+            &mut self,
+            track: Option<&[(u32, [f32; N])]>,
+            joint: gltf_json::Index<gltf_json::Node>,
+            property: gltf_json::animation::Property,
+            channels: &mut Vec<gltf_json::animation::Channel>,
+            samplers: &mut Vec<gltf_json::animation::Sampler>,
+        ) {
+            let Some(track) = track.filter(|t| !t.is_empty()) else {
+                return;
+            };
+
+            let times: Vec<f32> = track.iter().map(|(t, _)| *t as f32 / 1000.0).collect();
+            let values: Vec<[f32; N]> = track.iter().map(|(_, v)| *v).collect();
+
+            let input = self.push_scalar_f32_accessor(&times);
+            let output = self.push_accessor(
+                &values,
+                match N {
+                    3 => gltf_json::accessor::Type::Vec3,
+                    4 => gltf_json::accessor::Type::Vec4,
+                    _ => gltf_json::accessor::Type::Scalar,
+                },
+                None,
+            );
+
+            let sampler_index = gltf_json::Index::new(samplers.len() as u32);
+            samplers.push(gltf_json::animation::Sampler {
+                input,
+                interpolation: gltf_json::validation::Checked::Valid(
+                    gltf_json::animation::Interpolation::Linear,
+                ),
+                output,
+                extensions: Default::default(),
+                extras: Default::default(),
+            });
+            channels.push(gltf_json::animation::Channel {
+                sampler: sampler_index,
+                target: gltf_json::animation::Target {
+                    node: joint,
+                    path: gltf_json::validation::Checked::Valid(property),
+                    extensions: Default::default(),
+                    extras: Default::default(),
+                },
+                extensions: Default::default(),
+                extras: Default::default(),
+            });
+        }
+
+        /// Create one glTF material per model texture, in the same order as
+        /// `model.textures()`, so submeshes can reference them by index before any image
+        /// data has actually been decoded. [`Self::add_texture`] fills in the base color
+        /// texture on the matching material once the BLP is decoded.
+        pub fn add_materials(&mut self, textures: &[wow_m2::M2Texture]) {
+            for texture in textures {
+                let index = gltf_json::Index::new(self.root.materials.len() as u32);
+                self.root.materials.push(gltf_json::Material {
+                    name: Some(texture.filename().to_string()),
+                    ..Default::default()
+                });
+                self.texture_materials.push(index);
+            }
+        }
+
+        /// Decode a BLP texture to PNG, embed it as a glTF image + texture, and wire it up
+        /// as the base color texture of the material created for it by [`Self::add_materials`]
+        pub fn add_texture(&mut self, texture_index: usize, name: &str, blp: &wow_blp::BlpImage) {
+            let Ok(image) = wow_blp::convert::blp_to_image(blp, 0) else {
+                return;
+            };
+            let mut png = Vec::new();
+            if image
+                .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+                .is_err()
+            {
+                return;
+            }
+
+            let view = self.push_buffer_view(&png, None);
+            self.root.images.push(gltf_json::Image {
+                name: Some(name.to_string()),
+                buffer_view: Some(view),
+                mime_type: Some(gltf_json::image::MimeType("image/png".to_string())),
+                uri: None,
+                extensions: Default::default(),
+                extras: Default::default(),
+            });
+            let texture_gltf_index = gltf_json::Index::new(self.root.textures.len() as u32);
+            self.root.textures.push(gltf_json::Texture {
+                source: gltf_json::Index::new(self.root.images.len() as u32 - 1),
+                sampler: None,
+                name: Some(name.to_string()),
+                extensions: Default::default(),
+                extras: Default::default(),
+            });
+
+            if let Some(material_index) = self.texture_materials.get(texture_index) {
+                if let Some(material) = self.root.materials.get_mut(material_index.value() as usize) {
+                    material.pbr_metallic_roughness.base_color_texture = Some(gltf_json::texture::Info {
+                        index: texture_gltf_index,
+                        tex_coord: 0,
+                        extensions: Default::default(),
+                        extras: Default::default(),
+                    });
+                }
+            }
+        }
+
+        fn push_buffer_view(
+            &mut self,
+            bytes: &[u8],
+            target: Option<gltf_json::validation::Checked<gltf_json::buffer::Target>>,
+        ) -> gltf_json::Index<gltf_json::buffer::View> {
+            while self.binary.len() % 4 != 0 {
+                self.binary.push(0);
+            }
+            let offset = self.binary.len();
+            self.binary.extend_from_slice(bytes);
+
+            let index = gltf_json::Index::new(self.root.buffer_views.len() as u32);
+            self.root.buffer_views.push(gltf_json::buffer::View {
+                buffer: gltf_json::Index::new(0),
+                byte_offset: Some(offset as u32),
+                byte_length: bytes.len() as u32,
+                byte_stride: None,
+                target,
+                name: None,
+                extensions: Default::default(),
+                extras: Default::default(),
+            });
+            index
+        }
+
+        fn push_accessor<T: GltfComponent, const N: usize>(
+            &mut self,
+            values: &[[T; N]],
+            accessor_type: gltf_json::accessor::Type,
+            normalized: Option<bool>,
+        ) -> gltf_json::Index<gltf_json::accessor::Accessor> {
+            let bytes: Vec<u8> = values.iter().flatten().flat_map(|v| v.to_le_bytes()).collect();
+            let view = self.push_buffer_view(
+                &bytes,
+                Some(gltf_json::validation::Checked::Valid(
+                    gltf_json::buffer::Target::ArrayBuffer,
+                )),
+            );
+
+            let (min, max) = if accessor_type == gltf_json::accessor::Type::Vec3 && N == 3 {
+                component_bounds(values)
+            } else {
+                (None, None)
+            };
+
+            let index = gltf_json::Index::new(self.root.accessors.len() as u32);
+            self.root.accessors.push(gltf_json::accessor::Accessor {
+                buffer_view: Some(view),
+                byte_offset: Some(0),
+                count: values.len() as u32,
+                component_type: gltf_json::validation::Checked::Valid(
+                    gltf_json::accessor::GenericComponentType(T::COMPONENT_TYPE),
+                ),
+                extensions: Default::default(),
+                extras: Default::default(),
+                type_: gltf_json::validation::Checked::Valid(accessor_type),
+                min,
+                max,
+                name: None,
+                normalized: normalized.unwrap_or(false),
+                sparse: None,
+            });
+            index
+        }
+
+        fn push_scalar_accessor(
+            &mut self,
+            values: &[u16],
+            component_type: gltf_json::accessor::ComponentType,
+        ) -> gltf_json::Index<gltf_json::accessor::Accessor> {
+            let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+            let view = self.push_buffer_view(
+                &bytes,
+                Some(gltf_json::validation::Checked::Valid(
+                    gltf_json::buffer::Target::ElementArrayBuffer,
+                )),
+            );
+            let index = gltf_json::Index::new(self.root.accessors.len() as u32);
+            self.root.accessors.push(gltf_json::accessor::Accessor {
+                buffer_view: Some(view),
+                byte_offset: Some(0),
+                count: values.len() as u32,
+                component_type: gltf_json::validation::Checked::Valid(
+                    gltf_json::accessor::GenericComponentType(component_type),
+                ),
+                extensions: Default::default(),
+                extras: Default::default(),
+                type_: gltf_json::validation::Checked::Valid(gltf_json::accessor::Type::Scalar),
+                min: None,
+                max: None,
+                name: None,
+                normalized: false,
+                sparse: None,
+            });
+            index
+        }
+
+        fn push_scalar_f32_accessor(
+            &mut self,
+            values: &[f32],
+        ) -> gltf_json::Index<gltf_json::accessor::Accessor> {
+            let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+            let view = self.push_buffer_view(&bytes, None);
+            let min = values.iter().cloned().fold(f32::INFINITY, f32::min);
+            let max = values.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+            let index = gltf_json::Index::new(self.root.accessors.len() as u32);
+            self.root.accessors.push(gltf_json::accessor::Accessor {
+                buffer_view: Some(view),
+                byte_offset: Some(0),
+                count: values.len() as u32,
+                component_type: gltf_json::validation::Checked::Valid(
+                    gltf_json::accessor::GenericComponentType(
+                        gltf_json::accessor::ComponentType::F32,
+                    ),
+                ),
+                extensions: Default::default(),
+                extras: Default::default(),
+                type_: gltf_json::validation::Checked::Valid(gltf_json::accessor::Type::Scalar),
+                min: Some(serde_json::json!([min])),
+                max: Some(serde_json::json!([max])),
+                name: None,
+                normalized: false,
+                sparse: None,
+            });
+            index
+        }
+
+        pub fn write(&mut self, path: &Path) -> Result<()> {
+            while self.binary.len() % 4 != 0 {
+                self.binary.push(0);
+            }
+            // A .glb carries the blob in its BIN chunk; a .gltf points at a sibling .bin file
+            let is_glb = path.extension().and_then(|e| e.to_str()) == Some("glb");
+            let bin_path = path.with_extension("bin");
+            self.root.buffers.push(gltf_json::Buffer {
+                byte_length: self.binary.len() as u32,
+                uri: if is_glb {
+                    None
+                } else {
+                    bin_path.file_name().map(|n| n.to_string_lossy().into_owned())
+                },
+                name: None,
+                extensions: Default::default(),
+                extras: Default::default(),
+            });
+
+            if let Some(mesh_node) = self.mesh_node {
+                // Only un-parented (root) joints belong in `scene.nodes`; children are
+                // reached transitively through `Node::children`.
+                let mut scene_nodes: Vec<gltf_json::Index<gltf_json::Node>> = self
+                    .root_joints
+                    .iter()
+                    .copied()
+                    .collect();
+                scene_nodes.push(mesh_node);
+                self.root.scenes.push(gltf_json::Scene {
+                    nodes: scene_nodes,
+                    name: None,
+                    extensions: Default::default(),
+                    extras: Default::default(),
+                });
+                self.root.scene = Some(gltf_json::Index::new(0));
+            }
+
+            let json = serde_json::to_vec(&self.root)?;
+            if is_glb {
+                write_glb(path, &json, &self.binary)
+            } else {
+                std::fs::write(&bin_path, &self.binary)?;
+                std::fs::write(path, json).map_err(Into::into)
+            }
+        }
+    }
+
+    /// Types an accessor can be built from: little-endian bytes plus their glTF component tag
+    trait GltfComponent {
+        const COMPONENT_TYPE: gltf_json::accessor::ComponentType;
+        fn to_le_bytes(self) -> Vec<u8>;
+    }
+
+    impl GltfComponent for f32 {
+        const COMPONENT_TYPE: gltf_json::accessor::ComponentType =
+            gltf_json::accessor::ComponentType::F32;
+        fn to_le_bytes(self) -> Vec<u8> {
+            f32::to_le_bytes(self).to_vec()
+        }
+    }
+
+    impl GltfComponent for u16 {
+        const COMPONENT_TYPE: gltf_json::accessor::ComponentType =
+            gltf_json::accessor::ComponentType::U16;
+        fn to_le_bytes(self) -> Vec<u8> {
+            u16::to_le_bytes(self).to_vec()
+        }
+    }
+
+    fn component_bounds<const N: usize>(
+        values: &[[f32; N]],
+    ) -> (Option<serde_json::Value>, Option<serde_json::Value>) {
+        let mut min = [f32::INFINITY; N];
+        let mut max = [f32::NEG_INFINITY; N];
+        for value in values {
+            for i in 0..N {
+                min[i] = min[i].min(value[i]);
+                max[i] = max[i].max(value[i]);
+            }
+        }
+        (
+            Some(serde_json::json!(min.to_vec())),
+            Some(serde_json::json!(max.to_vec())),
+        )
+    }
+
+    /// 4x4 column-major translation matrix, inverted (i.e. negated translation)
+    fn inverse_translation_matrix(pivot: [f32; 3]) -> [f32; 16] {
+        let mut m = [0.0; 16];
+        m[0] = 1.0;
+        m[5] = 1.0;
+        m[10] = 1.0;
+        m[15] = 1.0;
+        m[12] = -pivot[0];
+        m[13] = -pivot[1];
+        m[14] = -pivot[2];
+        m
+    }
+
+    /// Write a two-chunk (JSON + binary) GLB container
+    fn write_glb(path: &Path, json: &[u8], binary: &[u8]) -> Result<()> {
+        use std::io::Write;
+
+        let mut json_chunk = json.to_vec();
+        json_chunk.resize(json_chunk.len().div_ceil(4) * 4, b' ');
+        let mut binary_chunk = binary.to_vec();
+        binary_chunk.resize(binary_chunk.len().div_ceil(4) * 4, 0);
+
+        let mut file = std::fs::File::create(path)?;
+        let total_len =
+            12 + 8 + json_chunk.len() as u32 + 8 + binary_chunk.len() as u32;
+
+        file.write_all(b"glTF")?;
+        file.write_all(&2u32.to_le_bytes())?;
+        file.write_all(&total_len.to_le_bytes())?;
+
+        file.write_all(&(json_chunk.len() as u32).to_le_bytes())?;
+        file.write_all(b"JSON")?;
+        file.write_all(&json_chunk)?;
+
+        file.write_all(&(binary_chunk.len() as u32).to_le_bytes())?;
+        file.write_all(b"BIN\0")?;
+        file.write_all(&binary_chunk)?;
+
+        Ok(())
+    }
+}
+
+/// A single companion file the model references, and whether it was located
+#[derive(Serialize)]
+struct DependencyReport {
+    kind: &'static str,
+    path: String,
+    present: bool,
+}
+
+fn handle_deps(path: PathBuf, resolve_dir: Option<PathBuf>) -> Result<()> {
+    println!("Loading M2 model: {}", path.display());
+
+    let m2_format = M2Model::load(&path)
+        .with_context(|| format!("Failed to load M2 model from {}", path.display()))?;
+    let model = m2_format.model();
+
+    let mut deps = Vec::new();
+
+    if let Some(skin_ids) = model.skin_file_ids() {
+        collect_file_id_deps(&mut deps, "skin", skin_ids.ids(), "skin", &resolve_dir);
+    } else {
+        collect_sibling_deps(&mut deps, "skin", &path, "00.skin");
+    }
+
+    if let Some(anim_ids) = model.anim_file_ids() {
+        collect_file_id_deps(&mut deps, "anim", anim_ids.ids(), "anim", &resolve_dir);
+    } else {
+        collect_anim_sibling_deps(&mut deps, &path, model.sequences());
+    }
+
+    // .bone/.phys/.skel are Legion+ FileDataID-only companions: classic models never have them
+    // as sibling files, so there is nothing to report when the *FID chunk is absent.
+    if let Some(bone_ids) = model.bone_file_ids() {
+        collect_file_id_deps(&mut deps, "bone", bone_ids.ids(), "bone", &resolve_dir);
+    }
+
+    if let Some(phys_id) = model.physics_file_id() {
+        collect_file_id_deps(&mut deps, "phys", &[phys_id.id()], "phys", &resolve_dir);
+    }
+
+    if let Some(skel_id) = model.skeleton_file_id() {
+        collect_file_id_deps(&mut deps, "skel", &[skel_id.id()], "skel", &resolve_dir);
+    }
+
+    if let Some(texture_ids) = model.texture_file_ids() {
+        collect_file_id_deps(&mut deps, "texture", texture_ids.ids(), "blp", &resolve_dir);
+    }
+
+    println!("\n=== Dependency Graph: {} ===", path.display());
+    let missing = deps.iter().filter(|d| !d.present).count();
+    for dep in &deps {
+        let status = if dep.present { "present" } else { "MISSING" };
+        println!("[{}] {} -> {status}", dep.kind, dep.path);
+    }
+    println!("\n{} dependencies, {missing} missing", deps.len());
+
+    Ok(())
+}
+
+/// Locate each FileDataID either by FileDataID-named file in `resolve_dir`, or report it unresolved
+fn collect_file_id_deps(
+    deps: &mut Vec<DependencyReport>,
+    kind: &'static str,
+    ids: &[u32],
+    extension: &str,
+    resolve_dir: &Option<PathBuf>,
+) {
+    for id in ids {
+        let (path, present) = match resolve_dir {
+            Some(dir) => {
+                let candidate = dir.join(format!("{id}.{extension}"));
+                let present = candidate.exists();
+                (candidate.display().to_string(), present)
+            }
+            None => (format!("FileDataID {id}"), false),
+        };
+        deps.push(DependencyReport {
+            kind,
+            path,
+            present,
+        });
+    }
+}
+
+/// Check for a classic filename-based companion file next to the model
+fn collect_sibling_deps(
+    deps: &mut Vec<DependencyReport>,
+    kind: &'static str,
+    m2_path: &std::path::Path,
+    suffix: &str,
+) {
+    let stem = m2_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let candidate = m2_path.with_file_name(format!("{stem}{suffix}"));
+    let present = candidate.exists();
+    deps.push(DependencyReport {
+        kind,
+        path: candidate.display().to_string(),
+        present,
+    });
+}
+
+/// A set sequence flag bit means the sequence's keyframe data lives inline in the model
+/// instead of a companion `.anim` file, so only sequences with this bit clear have a
+/// sibling to report
+const SEQUENCE_FLAG_EMBEDDED_DATA: u16 = 0x20;
+
+/// Check for classic per-sequence `.anim` files, named `<Model><animId>-<subAnimId>.anim`
+/// (zero-padded to 4 and 2 digits respectively). Sequences whose data is embedded in the
+/// model (`flags & SEQUENCE_FLAG_EMBEDDED_DATA`) have no such sibling file to report.
+fn collect_anim_sibling_deps(
+    deps: &mut Vec<DependencyReport>,
+    m2_path: &std::path::Path,
+    sequences: &[wow_m2::M2Sequence],
+) {
+    let stem = m2_path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    for sequence in sequences {
+        if sequence.flags & SEQUENCE_FLAG_EMBEDDED_DATA != 0 {
+            continue;
+        }
+
+        let suffix = format!("{:04}-{:02}.anim", sequence.id, sequence.variation_index);
+        let candidate = m2_path.with_file_name(format!("{stem}{suffix}"));
+        let present = candidate.exists();
+        deps.push(DependencyReport {
+            kind: "anim",
+            path: candidate.display().to_string(),
+            present,
+        });
+    }
+}
+
+fn handle_batch_validate(root: PathBuf, show_warnings: bool, format: OutputFormat) -> Result<()> {
+    if matches!(format, OutputFormat::Text) {
+        println!("Scanning {} for M2 assets...", root.display());
+    }
+
+    let mut files = Vec::new();
+    for entry in WalkDir::new(&root)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_file())
+    {
+        let path = entry.path();
+        let Some(extension) = path.extension().and_then(|e| e.to_str()) else {
+            continue;
+        };
+
+        let result = match extension.to_lowercase().as_str() {
+            "m2" => Some(validate_m2_file(path)),
+            "skin" => Some(validate_skin_file(path)),
+            "anim" => Some(validate_anim_file(path)),
+            "blp" => Some(validate_blp_file(path)),
+            _ => None,
+        };
+
+        if let Some(result) = result {
+            files.push(result);
+        }
+    }
+
+    let failed_count = files.iter().filter(|file| !file.passed).count();
+    let mut failures_by_file_type = std::collections::BTreeMap::new();
+    let mut failures_by_category = std::collections::BTreeMap::new();
+    for file in files.iter().filter(|file| !file.passed) {
+        *failures_by_file_type
+            .entry(file.file_type.clone())
+            .or_insert(0usize) += 1;
+        if let Some(category) = &file.error_category {
+            *failures_by_category.entry(category.clone()).or_insert(0usize) += 1;
+        }
+    }
+
+    let report = BatchValidateReport {
+        root: root.display().to_string(),
+        total: files.len(),
+        passed: files.len() - failed_count,
+        failed: failed_count,
+        failures_by_file_type,
+        failures_by_category,
+        files,
+    };
+
+    format.emit(&report, |report| {
+        println!("\n=== Batch Validation: {} ===", report.root);
+        println!("Total files: {}", report.total);
+        println!("Passed: {}", report.passed);
+        println!("Failed: {}", report.failed);
+
+        if !report.failures_by_file_type.is_empty() {
+            println!("\n=== Failures by File Type ===");
+            for (file_type, count) in &report.failures_by_file_type {
+                println!("{file_type}: {count}");
+            }
+        }
+
+        if !report.failures_by_category.is_empty() {
+            println!("\n=== Failures by Category ===");
+            for (category, count) in &report.failures_by_category {
+                println!("{category}: {count}");
+            }
+        }
+
+        println!("\n=== Per-file Detail ===");
+        for file in &report.files {
+            if file.passed && !show_warnings {
+                continue;
+            }
+            let status = if file.passed { "OK" } else { "FAILED" };
+            println!("[{status}] {} ({})", file.path, file.file_type);
+            if let Some(error) = &file.error {
+                println!("    {error}");
+            }
+        }
+    })?;
+
+    if report.failed > 0 {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+fn file_validation_report(path: &Path, file_type: &str, error: Option<String>) -> FileValidationReport {
+    let error_category = error.as_deref().map(classify_validation_error);
+    FileValidationReport {
+        path: path.display().to_string(),
+        file_type: file_type.to_string(),
+        passed: error.is_none(),
+        error,
+        error_category,
+    }
+}
+
+fn validate_m2_file(path: &Path) -> FileValidationReport {
+    let error = match M2Model::load(path) {
+        Ok(m2_format) => m2_format.model().validate().err().map(|e| e.to_string()),
+        Err(e) => Some(e.to_string()),
+    };
+    file_validation_report(path, "m2", error)
+}
+
+fn validate_skin_file(path: &Path) -> FileValidationReport {
+    let error = SkinG::<SkinHeader>::load(path).err().map(|e| e.to_string());
+    file_validation_report(path, "skin", error)
+}
+
+fn validate_anim_file(path: &Path) -> FileValidationReport {
+    let error = AnimFile::load(path).err().map(|e| e.to_string());
+    file_validation_report(path, "anim", error)
+}
+
+fn validate_blp_file(path: &Path) -> FileValidationReport {
+    let error = load_blp(path).err().map(|e| e.to_string());
+    file_validation_report(path, "blp", error)
+}
+
+fn handle_blp_convert(input: PathBuf, output: PathBuf, mip: Option<usize>) -> Result<()> {
+    println!("Loading BLP texture: {}", input.display());
+
+    let blp = load_blp(&input)
+        .with_context(|| format!("Failed to load BLP texture from {}", input.display()))?;
+
+    let mip_level = mip.unwrap_or(0);
+    println!("Decoding mip level {mip_level}...");
+    let image = wow_blp::convert::blp_to_image(&blp, mip_level)
+        .with_context(|| format!("Failed to decode mip level {mip_level}"))?;
+
+    println!("Saving image to: {}", output.display());
+    image
+        .save(&output)
+        .with_context(|| format!("Failed to save image to {}", output.display()))?;
+
+    println!("Conversion complete!");
     Ok(())
 }